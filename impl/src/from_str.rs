@@ -2,7 +2,7 @@
 
 #[cfg(doc)]
 use std::str::FromStr;
-use std::{collections::HashMap, iter};
+use std::{collections::HashMap, iter, mem};
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -15,6 +15,8 @@ pub fn expand(input: &syn::DeriveInput, _: &'static str) -> syn::Result<TokenStr
     match &input.data {
         syn::Data::Struct(data) => Ok(if data.fields.is_empty() {
             FlatExpansion::try_from(input)?.into_token_stream()
+        } else if Attrs::parse(&input.attrs)?.format.is_some() {
+            FormatExpansion::try_from(input)?.into_token_stream()
         } else {
             ForwardExpansion::try_from(input)?.into_token_stream()
         }),
@@ -33,8 +35,16 @@ struct ForwardExpansion<'i> {
     /// [`syn::Ident`]: struct@syn::Ident
     self_ty: (&'i syn::Ident, &'i syn::Generics),
 
-    /// [`syn::Field`] representing the wrapped type to forward implementation on.
-    inner: &'i syn::Field,
+    /// [`syn::DataStruct`] the implementation is forwarded on.
+    data: &'i syn::DataStruct,
+
+    /// Index (into `data`'s fields) of the field whose type receives the parsed value.
+    parsed: usize,
+
+    /// [`ForwardFieldAttrs`] of every field, aligned by index with `data`'s fields.
+    ///
+    /// The field at `parsed` always holds the default (empty) [`ForwardFieldAttrs`].
+    attrs: Vec<ForwardFieldAttrs>,
 }
 
 impl<'i> TryFrom<&'i syn::DeriveInput> for ForwardExpansion<'i> {
@@ -48,23 +58,44 @@ impl<'i> TryFrom<&'i syn::DeriveInput> for ForwardExpansion<'i> {
             ));
         };
 
-        // TODO: Unite these two conditions via `&&` once MSRV is bumped to 1.88 or above.
-        if data.fields.len() != 1 {
+        if data.fields.is_empty() {
             return Err(syn::Error::new(
                 data.fields.span(),
-                "only structs with single field can derive `FromStr`",
+                "expected a struct with at least one field for forward `FromStr` derive",
             ));
         }
-        let Some(inner) = data.fields.iter().next() else {
+
+        let attrs = data
+            .fields
+            .iter()
+            .map(|f| ForwardFieldAttrs::parse(&f.attrs))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let mut unannotated = attrs
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| !a.default && a.value.is_none())
+            .map(|(i, _)| i);
+        let Some(parsed) = unannotated.next() else {
             return Err(syn::Error::new(
                 data.fields.span(),
-                "only structs with single field can derive `FromStr`",
+                "exactly one field must be left without `#[from_str(default)]` or \
+                 `#[from_str(value = \"...\")]` to receive the parsed value, but none was",
             ));
         };
+        if unannotated.next().is_some() {
+            return Err(syn::Error::new(
+                data.fields.span(),
+                "exactly one field must be left without `#[from_str(default)]` or \
+                 `#[from_str(value = \"...\")]` to receive the parsed value, but multiple were",
+            ));
+        }
 
         Ok(Self {
             self_ty: (&input.ident, &input.generics),
-            inner,
+            data,
+            parsed,
+            attrs,
         })
     }
 }
@@ -72,7 +103,13 @@ impl<'i> TryFrom<&'i syn::DeriveInput> for ForwardExpansion<'i> {
 impl ToTokens for ForwardExpansion<'_> {
     /// Expands a forwarding [`FromStr`] implementations for a struct.
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let inner_ty = &self.inner.ty;
+        let parsed_field = self
+            .data
+            .fields
+            .iter()
+            .nth(self.parsed)
+            .expect("`parsed` index validated in `TryFrom`");
+        let inner_ty = &parsed_field.ty;
         let ty = self.self_ty.0;
 
         let mut generics = self.self_ty.1.clone();
@@ -83,7 +120,16 @@ impl ToTokens for ForwardExpansion<'_> {
         }
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-        let constructor = self.inner.self_constructor([parse_quote! { v }]);
+        let values = self.attrs.iter().enumerate().map(|(i, attrs)| {
+            if i == self.parsed {
+                quote! { v }
+            } else if let Some(value) = &attrs.value {
+                quote! { #value }
+            } else {
+                quote! { derive_more::core::default::Default::default() }
+            }
+        });
+        let constructor = self.data.self_constructor(values);
 
         quote! {
             #[automatically_derived]
@@ -99,6 +145,340 @@ impl ToTokens for ForwardExpansion<'_> {
     }
 }
 
+/// `#[from_str(default)]`/`#[from_str(value = "...")]` attributes of a [`syn::Field`] not
+/// receiving the parsed value in a [`ForwardExpansion`].
+#[derive(Default)]
+struct ForwardFieldAttrs {
+    /// Set via `#[from_str(default)]`: populate this field with `Default::default()`.
+    default: bool,
+
+    /// Set via `#[from_str(value = "...")]`: populate this field with the given expression.
+    value: Option<syn::Expr>,
+}
+
+impl ForwardFieldAttrs {
+    /// Parses the `#[from_str(...)]` attributes of a single field.
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("from_str") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    out.default = true;
+                    Ok(())
+                } else if meta.path.is_ident("value") {
+                    out.value = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `from_str` field attribute"))
+                }
+            })?;
+        }
+        if out.default && out.value.is_some() {
+            return Err(syn::Error::new_spanned(
+                &attrs[0],
+                "`#[from_str(default)]` and `#[from_str(value = \"...\")]` are mutually exclusive",
+            ));
+        }
+        Ok(out)
+    }
+}
+
+/// Expansion of a macro for generating a [`FromStr`] implementation of a struct from a
+/// `#[from_str(format = "...")]` template: the inverse of the `Display` derive's format string.
+struct FormatExpansion<'i> {
+    /// [`syn::Ident`] and [`syn::Generics`] of the struct.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    self_ty: (&'i syn::Ident, &'i syn::Generics),
+
+    /// [`syn::DataStruct`] being populated.
+    data: &'i syn::DataStruct,
+
+    /// Parsed `#[from_str(format = "...")]` template.
+    segments: Vec<FormatSegment>,
+}
+
+impl<'i> TryFrom<&'i syn::DeriveInput> for FormatExpansion<'i> {
+    type Error = syn::Error;
+
+    fn try_from(input: &'i syn::DeriveInput) -> syn::Result<Self> {
+        let syn::Data::Struct(data) = &input.data else {
+            return Err(syn::Error::new(
+                input.span(),
+                "expected a struct for format `FromStr` derive",
+            ));
+        };
+
+        let format = Attrs::parse(&input.attrs)?.format.ok_or_else(|| {
+            syn::Error::new(
+                input.span(),
+                "expected a `#[from_str(format = \"...\")]` attribute",
+            )
+        })?;
+        let segments = parse_format(&format, &data.fields)?;
+        check_format_covers_fields(&segments, &data.fields)?;
+
+        Ok(Self {
+            self_ty: (&input.ident, &input.generics),
+            data,
+            segments,
+        })
+    }
+}
+
+impl ToTokens for FormatExpansion<'_> {
+    /// Expands a format-driven [`FromStr`] implementation for a struct.
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = self.self_ty.0;
+        let (impl_generics, ty_generics, where_clause) = self.self_ty.1.split_for_impl();
+        let ty_name = ty.to_string();
+
+        let fields = self.data.fields.iter().collect::<Vec<_>>();
+        let mut stmts = Vec::new();
+        let mut values: Vec<Option<syn::Ident>> = vec![None; fields.len()];
+
+        let mut i = 0;
+        while i < self.segments.len() {
+            match &self.segments[i] {
+                FormatSegment::Literal(lit) => {
+                    stmts.push(quote! {
+                        let rest = rest.strip_prefix(#lit).ok_or_else(
+                            || derive_more::FromStrError::new(#ty_name),
+                        )?;
+                    });
+                    i += 1;
+                }
+                FormatSegment::Field(idx) => {
+                    let field_ty = &fields[*idx].ty;
+                    let value_ident =
+                        syn::Ident::new(&format!("__value_{idx}"), proc_macro2::Span::call_site());
+
+                    let separator = match self.segments.get(i + 1) {
+                        Some(FormatSegment::Literal(lit)) => Some(lit),
+                        _ => None,
+                    };
+                    if let Some(separator) = separator {
+                        stmts.push(quote! {
+                            let (__fragment, rest) = rest.split_once(#separator).ok_or_else(
+                                || derive_more::FromStrError::new(#ty_name),
+                            )?;
+                            let #value_ident: #field_ty = __fragment.parse().map_err(
+                                |_| derive_more::FromStrError::new(#ty_name),
+                            )?;
+                        });
+                        i += 2;
+                    } else {
+                        stmts.push(quote! {
+                            let #value_ident: #field_ty = rest.parse().map_err(
+                                |_| derive_more::FromStrError::new(#ty_name),
+                            )?;
+                            let rest = "";
+                        });
+                        i += 1;
+                    }
+
+                    values[*idx] = Some(value_ident);
+                }
+            }
+        }
+        let values = values
+            .into_iter()
+            .map(|v| v.expect("every field covered, as validated in `TryFrom`"));
+        let constructor = self.data.self_constructor(values);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics derive_more::core::str::FromStr for #ty #ty_generics #where_clause {
+                type Err = derive_more::FromStrError;
+
+                fn from_str(s: &str) -> derive_more::core::result::Result<Self, Self::Err> {
+                    let rest = s;
+                    #( #stmts )*
+                    if !rest.is_empty() {
+                        return derive_more::core::result::Result::Err(
+                            derive_more::FromStrError::new(#ty_name),
+                        );
+                    }
+                    derive_more::core::result::Result::Ok(#constructor)
+                }
+            }
+        }.to_tokens(tokens);
+    }
+}
+
+/// A single segment of a parsed `#[from_str(format = "...")]` template.
+enum FormatSegment {
+    /// Literal separator text, matched verbatim.
+    Literal(String),
+    /// `{field}`/`{0}`/`{}` placeholder, resolved to an index into the struct's fields.
+    Field(usize),
+}
+
+/// Parses a `#[from_str(format = "...")]` template into alternating [`FormatSegment`]s, resolving
+/// each `{...}` placeholder against `fields`.
+fn parse_format(lit: &syn::LitStr, fields: &syn::Fields) -> syn::Result<Vec<FormatSegment>> {
+    let raw = lit.value();
+    let mut chars = raw.char_indices().peekable();
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut next_positional = 0usize;
+
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '{' => {
+                if !literal.is_empty() {
+                    segments.push(FormatSegment::Literal(mem::take(&mut literal)));
+                }
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '}')) => break,
+                        Some((_, c)) => name.push(c),
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "unterminated `{` in `#[from_str(format = \"...\")]`",
+                            ))
+                        }
+                    }
+                }
+                let index = resolve_placeholder(&name, fields, &mut next_positional, lit)?;
+                segments.push(FormatSegment::Field(index));
+            }
+            '}' if chars.peek().map(|&(_, c)| c) == Some('}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "unmatched `}` in `#[from_str(format = \"...\")]`",
+                ))
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(FormatSegment::Literal(literal));
+    }
+
+    if segments
+        .windows(2)
+        .any(|w| matches!(w, [FormatSegment::Field(_), FormatSegment::Field(_)]))
+    {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "adjacent `{...}` placeholders in `#[from_str(format = \"...\")]` are ambiguous: \
+             separate them with a literal",
+        ));
+    }
+
+    Ok(segments)
+}
+
+/// Builds the soft-fallback (`Option`-returning) statements that parse `rest` according to
+/// `segments`, assigning each matched field's value to a `__value_{idx}` binding.
+///
+/// Mirrors the hard-error statements built by [`FormatExpansion::to_tokens`], but using `?`
+/// against [`Option`] instead of erroring immediately, so a caller can fall through to trying
+/// another variant on mismatch.
+fn format_segment_attempt_stmts(segments: &[FormatSegment], fields: &[&syn::Field]) -> Vec<TokenStream> {
+    let mut stmts = Vec::new();
+
+    let mut i = 0;
+    while i < segments.len() {
+        match &segments[i] {
+            FormatSegment::Literal(lit) => {
+                stmts.push(quote! {
+                    let rest = rest.strip_prefix(#lit)?;
+                });
+                i += 1;
+            }
+            FormatSegment::Field(idx) => {
+                let field_ty = &fields[*idx].ty;
+                let value_ident =
+                    syn::Ident::new(&format!("__value_{idx}"), proc_macro2::Span::call_site());
+
+                let separator = match segments.get(i + 1) {
+                    Some(FormatSegment::Literal(lit)) => Some(lit),
+                    _ => None,
+                };
+                if let Some(separator) = separator {
+                    stmts.push(quote! {
+                        let (__fragment, rest) = rest.split_once(#separator)?;
+                        let #value_ident: #field_ty = __fragment.parse().ok()?;
+                    });
+                    i += 2;
+                } else {
+                    stmts.push(quote! {
+                        let #value_ident: #field_ty = rest.parse().ok()?;
+                        let rest = "";
+                    });
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    stmts
+}
+
+/// Resolves a placeholder's inner text (`"x"`, `"0"`, or `""`) to a field index.
+fn resolve_placeholder(
+    name: &str,
+    fields: &syn::Fields,
+    next_positional: &mut usize,
+    lit: &syn::LitStr,
+) -> syn::Result<usize> {
+    let index = if name.is_empty() {
+        let index = *next_positional;
+        *next_positional += 1;
+        index
+    } else if let Ok(index) = name.parse::<usize>() {
+        index
+    } else {
+        fields
+            .iter()
+            .position(|f| f.ident.as_ref().is_some_and(|ident| ident == name))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(lit, format!("no field named `{name}` to format"))
+            })?
+    };
+    if index >= fields.len() {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!("field index `{index}` is out of range"),
+        ));
+    }
+    Ok(index)
+}
+
+/// Checks that every field of `fields` is mentioned by some [`FormatSegment::Field`] in
+/// `segments`, erroring on the first field that is not.
+fn check_format_covers_fields(segments: &[FormatSegment], fields: &syn::Fields) -> syn::Result<()> {
+    let mut covered = vec![false; fields.len()];
+    for segment in segments {
+        if let FormatSegment::Field(i) = segment {
+            covered[*i] = true;
+        }
+    }
+    if let Some(i) = covered.iter().position(|covered| !covered) {
+        let field = fields.iter().nth(i).expect("index within bounds");
+        return Err(syn::Error::new(
+            field.span(),
+            "field is not mentioned in the `#[from_str(format = \"...\")]` template",
+        ));
+    }
+    Ok(())
+}
+
 /// Expansion of a macro for generating a flat [`FromStr`] implementation of an enum or a struct.
 struct FlatExpansion<'i> {
     /// [`syn::Ident`] and [`syn::Generics`] of the enum/struct.
@@ -106,19 +486,62 @@ struct FlatExpansion<'i> {
     /// [`syn::Ident`]: struct@syn::Ident
     self_ty: (&'i syn::Ident, &'i syn::Generics),
 
-    /// [`syn::Ident`]s along with the matched values (enum variants or struct itself).
+    /// [`RenameAll`] case conversion applied to every matched name, as set via a
+    /// `#[from_str(rename_all = "...")]` attribute on the enum/struct.
+    ///
+    /// [`None`] preserves the historical case-insensitive matching.
+    rename_all: Option<RenameAll>,
+
+    /// Whether `s` should first be tried as an integer discriminant, as set via a
+    /// `#[from_str(numeric)]` attribute on the enum.
+    numeric: bool,
+
+    /// Matched enum variants (or the struct itself), along with their `#[from_str(...)]`
+    /// attributes.
+    matches: Vec<Match<'i>>,
+}
+
+/// A single matched value of a [`FlatExpansion`]: an enum variant, or a unit struct standing in
+/// for the sole matched value.
+struct Match<'i> {
+    /// [`syn::Ident`] of the variant/struct.
     ///
     /// [`syn::Ident`]: struct@syn::Ident
-    matches: Vec<(
-        &'i syn::Ident,
-        Either<&'i syn::DataStruct, &'i syn::Variant>,
-    )>,
+    ident: &'i syn::Ident,
+
+    /// Fields of the variant/struct, used to build its constructor.
+    value: Either<&'i syn::DataStruct, &'i syn::Variant>,
+
+    /// `#[from_str(rename = "...", alias = "...")]` attributes of this match.
+    attrs: Attrs,
+
+    /// Integer discriminant of an enum variant, explicit or implicit, used when matching `s` as
+    /// a number. [`None`] for a struct, which has no discriminant, and for every variant of an
+    /// enum without `#[from_str(numeric)]`.
+    discriminant: Option<i128>,
+
+    /// Parsed `#[from_str(format = "...")]` template matching this variant's fields, as set via
+    /// a variant-level `format = "..."`. [`None`] matches on the name instead, as usual.
+    format: Option<Vec<FormatSegment>>,
+}
+
+impl Match<'_> {
+    /// Returns the canonical name of this match, honoring a `rename`, if any.
+    fn name(&self) -> String {
+        self.attrs
+            .rename
+            .as_ref()
+            .map(syn::LitStr::value)
+            .unwrap_or_else(|| self.ident.to_string())
+    }
 }
 
 impl<'i> TryFrom<&'i syn::DeriveInput> for FlatExpansion<'i> {
     type Error = syn::Error;
 
     fn try_from(input: &'i syn::DeriveInput) -> syn::Result<Self> {
+        let container_attrs = Attrs::parse(&input.attrs)?;
+
         let matches = match &input.data {
             syn::Data::Struct(data) => {
                 if !data.fields.is_empty() {
@@ -127,21 +550,97 @@ impl<'i> TryFrom<&'i syn::DeriveInput> for FlatExpansion<'i> {
                         "only structs with no fields can derive `FromStr`",
                     ));
                 }
-                vec![(&input.ident, Either::Left(data))]
+                if container_attrs.numeric {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "`#[from_str(numeric)]` is only applicable to enums",
+                    ));
+                }
+                if container_attrs.format.is_some() {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "`#[from_str(format = \"...\")]` has no effect on a fieldless struct",
+                    ));
+                }
+                vec![Match {
+                    ident: &input.ident,
+                    value: Either::Left(data),
+                    attrs: container_attrs.clone(),
+                    discriminant: None,
+                    format: None,
+                }]
+            }
+            syn::Data::Enum(data) => {
+                let mut next_discriminant = 0i128;
+                data.variants
+                    .iter()
+                    .map(|variant| {
+                        let variant_attrs = Attrs::parse(&variant.attrs)?;
+                        if variant_attrs.rename_all.is_some() {
+                            return Err(syn::Error::new(
+                                variant.span(),
+                                "`#[from_str(rename_all = \"...\")]` is only applicable to the \
+                                 container, not individual variants",
+                            ));
+                        }
+                        if variant_attrs.numeric {
+                            return Err(syn::Error::new(
+                                variant.span(),
+                                "`#[from_str(numeric)]` is only applicable to the container, \
+                                 not individual variants",
+                            ));
+                        }
+
+                        let format = variant_attrs
+                            .format
+                            .as_ref()
+                            .map(|lit| -> syn::Result<_> {
+                                let segments = parse_format(lit, &variant.fields)?;
+                                check_format_covers_fields(&segments, &variant.fields)?;
+                                Ok(segments)
+                            })
+                            .transpose()?;
+
+                        if !variant.fields.is_empty() && format.is_none() {
+                            return Err(syn::Error::new(
+                                variant.fields.span(),
+                                "only enums with no fields can derive `FromStr`, unless the \
+                                 variant carries a `#[from_str(format = \"...\")]` attribute",
+                            ));
+                        }
+                        if format.is_some() && container_attrs.numeric {
+                            return Err(syn::Error::new(
+                                variant.span(),
+                                "`#[from_str(numeric)]` cannot be combined with a variant \
+                                 carrying `#[from_str(format = \"...\")]`, since it has no \
+                                 integer discriminant",
+                            ));
+                        }
+                        if format.is_some()
+                            && (variant_attrs.rename.is_some() || !variant_attrs.aliases.is_empty())
+                        {
+                            return Err(syn::Error::new(
+                                variant.span(),
+                                "`#[from_str(rename = \"...\")]`/`#[from_str(alias = \"...\")]` \
+                                 have no effect on a variant carrying \
+                                 `#[from_str(format = \"...\")]`, which isn't matched by name",
+                            ));
+                        }
+
+                        let discriminant = container_attrs
+                            .numeric
+                            .then(|| variant_discriminant(variant, &mut next_discriminant))
+                            .transpose()?;
+                        Ok(Match {
+                            ident: &variant.ident,
+                            value: Either::Right(variant),
+                            attrs: variant_attrs,
+                            discriminant,
+                            format,
+                        })
+                    })
+                    .collect::<syn::Result<_>>()?
             }
-            syn::Data::Enum(data) => data
-                .variants
-                .iter()
-                .map(|variant| {
-                    if !variant.fields.is_empty() {
-                        return Err(syn::Error::new(
-                            variant.fields.span(),
-                            "only enums with no fields can derive `FromStr`",
-                        ));
-                    }
-                    Ok((&variant.ident, Either::Right(variant)))
-                })
-                .collect::<syn::Result<_>>()?,
             syn::Data::Union(_) => {
                 return Err(syn::Error::new(
                     input.span(),
@@ -150,13 +649,74 @@ impl<'i> TryFrom<&'i syn::DeriveInput> for FlatExpansion<'i> {
             }
         };
 
+        if let Some(case) = container_attrs.rename_all {
+            let mut seen = HashMap::<String, &syn::Ident>::new();
+            for m in matches.iter().filter(|m| m.format.is_none()) {
+                let names = iter::once(
+                    m.attrs
+                        .rename
+                        .as_ref()
+                        .map(syn::LitStr::value)
+                        .unwrap_or_else(|| case.convert(&m.ident.to_string())),
+                )
+                .chain(m.attrs.aliases.iter().map(syn::LitStr::value));
+                for name in names {
+                    if let Some(prev) = seen.insert(name.clone(), m.ident) {
+                        return Err(syn::Error::new(
+                            m.ident.span(),
+                            format!(
+                                "`{}` and `{prev}` both match `{name}` under the container's \
+                                 `#[from_str(rename_all = \"...\")]` case conversion",
+                                m.ident,
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
         Ok(Self {
             self_ty: (&input.ident, &input.generics),
+            rename_all: container_attrs.rename_all,
+            numeric: container_attrs.numeric,
             matches,
         })
     }
 }
 
+/// Computes the integer discriminant of `variant`, explicit or implicit, advancing
+/// `next_discriminant` to the value the following variant would implicitly receive.
+fn variant_discriminant(
+    variant: &syn::Variant,
+    next_discriminant: &mut i128,
+) -> syn::Result<i128> {
+    let discriminant = match &variant.discriminant {
+        Some((_, expr)) => literal_discriminant(expr)?,
+        None => *next_discriminant,
+    };
+    *next_discriminant = discriminant + 1;
+    Ok(discriminant)
+}
+
+/// Evaluates a discriminant expression that is an (optionally negated) integer literal.
+fn literal_discriminant(expr: &syn::Expr) -> syn::Result<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_discriminant(expr).map(|v| -v),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "only integer literal discriminants are supported by `#[from_str(numeric)]`",
+        )),
+    }
+}
+
 impl ToTokens for FlatExpansion<'_> {
     /// Expands a flat [`FromStr`] implementations for an enum.
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -165,24 +725,110 @@ impl ToTokens for FlatExpansion<'_> {
             self.self_ty.1.split_for_impl();
         let ty_name = ty.to_string();
 
-        let similar_lowercased = self
-            .matches
-            .iter()
-            .map(|(v, _)| v.to_string().to_lowercase())
-            .fold(<HashMap<_, u8>>::new(), |mut counts, v| {
-                *counts.entry(v).or_default() += 1;
-                counts
+        let flat_matches = self.matches.iter().filter(|m| m.format.is_none()).collect::<Vec<_>>();
+
+        let (scrutinee, match_arms) = if let Some(case) = self.rename_all {
+            let match_arms = flat_matches.iter().flat_map(|m| {
+                let constructor = m.value.self_constructor_empty();
+                let name = m
+                    .attrs
+                    .rename
+                    .as_ref()
+                    .map(syn::LitStr::value)
+                    .unwrap_or_else(|| case.convert(&m.ident.to_string()));
+
+                let mut arms = vec![quote! { #name => #constructor, }];
+                arms.extend(m.attrs.aliases.iter().map(|alias| {
+                    let alias = alias.value();
+                    quote! { #alias => #constructor, }
+                }));
+                arms
+            });
+
+            (quote! { s }, quote! { #( #match_arms )* })
+        } else {
+            let similar_lowercased = flat_matches
+                .iter()
+                .flat_map(|m| {
+                    iter::once(m.name().to_lowercase())
+                        .chain(m.attrs.aliases.iter().map(|a| a.value().to_lowercase()))
+                })
+                .fold(<HashMap<_, u8>>::new(), |mut counts, v| {
+                    *counts.entry(v).or_default() += 1;
+                    counts
+                });
+
+            let match_arms = flat_matches.iter().flat_map(|m| {
+                let constructor = m.value.self_constructor_empty();
+                let name = m.name();
+                let lowercased = name.to_lowercase();
+
+                let exact_guard =
+                    (similar_lowercased[&lowercased] > 1).then(|| quote! { if s == #name });
+
+                let mut arms = vec![quote! { #lowercased #exact_guard => #constructor, }];
+                arms.extend(m.attrs.aliases.iter().map(|alias| {
+                    let alias = alias.value();
+                    let alias_lowercased = alias.to_lowercase();
+                    let exact_guard = (similar_lowercased[&alias_lowercased] > 1)
+                        .then(|| quote! { if s == #alias });
+                    quote! { #alias_lowercased #exact_guard => #constructor, }
+                }));
+                arms
             });
 
-        let match_arms = self.matches.iter().map(|(ident, value)| {
-            let name = ident.to_string();
-            let lowercased = name.to_lowercase();
+            (quote! { s.to_lowercase().as_str() }, quote! { #( #match_arms )* })
+        };
 
-            let exact_guard =
-                (similar_lowercased[&lowercased] > 1).then(|| quote! { if s == #name });
-            let constructor = value.self_constructor_empty();
+        let numeric_attempt = self.numeric.then(|| {
+            let numeric_arms = flat_matches.iter().map(|m| {
+                let discriminant = m
+                    .discriminant
+                    .expect("enum variants always have a discriminant");
+                let constructor = m.value.self_constructor_empty();
+                quote! { #discriminant => #constructor, }
+            });
 
-            quote! { #lowercased #exact_guard => #constructor, }
+            quote! {
+                if let derive_more::core::result::Result::Ok(n) = s.parse::<i128>() {
+                    return derive_more::core::result::Result::Ok(match n {
+                        #( #numeric_arms )*
+                        _ => return derive_more::core::result::Result::Err(
+                            derive_more::FromStrError::new(#ty_name),
+                        ),
+                    });
+                }
+            }
+        });
+
+        let format_attempts = self.matches.iter().filter_map(|m| {
+            let segments = m.format.as_ref()?;
+            let fields = match &m.value {
+                Either::Left(data) => data.fields.iter().collect::<Vec<_>>(),
+                Either::Right(variant) => variant.fields.iter().collect::<Vec<_>>(),
+            };
+            let stmts = format_segment_attempt_stmts(segments, &fields);
+            let constructor = m.value.self_constructor(
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, _)| syn::Ident::new(&format!("__value_{i}"), proc_macro2::Span::call_site())),
+            );
+
+            Some(quote! {
+                if let derive_more::core::option::Option::Some(__v) =
+                    (|| -> derive_more::core::option::Option<Self> {
+                        let rest = s;
+                        #( #stmts )*
+                        if !rest.is_empty() {
+                            return derive_more::core::option::Option::None;
+                        }
+                        derive_more::core::option::Option::Some(#constructor)
+                    })()
+                {
+                    return derive_more::core::result::Result::Ok(__v);
+                }
+            })
         });
 
         quote! {
@@ -194,8 +840,10 @@ impl ToTokens for FlatExpansion<'_> {
                 fn from_str(
                     s: &str,
                 ) -> derive_more::core::result::Result<Self, derive_more::FromStrError> {
-                    derive_more::core::result::Result::Ok(match s.to_lowercase().as_str() {
-                        #( #match_arms )*
+                    #numeric_attempt
+                    #( #format_attempts )*
+                    derive_more::core::result::Result::Ok(match #scrutinee {
+                        #match_arms
                         _ => return derive_more::core::result::Result::Err(
                             derive_more::FromStrError::new(#ty_name),
                         ),
@@ -206,6 +854,171 @@ impl ToTokens for FlatExpansion<'_> {
     }
 }
 
+/// Case convention a variant/struct name is rewritten into before being matched against the
+/// input string, as specified via `#[from_str(rename_all = "...")]`.
+#[derive(Clone, Copy)]
+enum RenameAll {
+    /// `lowercase`.
+    Lower,
+    /// `snake_case`.
+    Snake,
+    /// `kebab-case`.
+    Kebab,
+    /// `SCREAMING_SNAKE_CASE`.
+    ScreamingSnake,
+    /// `PascalCase`.
+    Pascal,
+    /// `camelCase`.
+    Camel,
+}
+
+impl RenameAll {
+    /// Converts the provided `ident` (written in `PascalCase`, `snake_case` or `kebab-case`, as
+    /// split by [`split_ident_words()`]) into the case convention represented by this
+    /// [`RenameAll`].
+    fn convert(self, ident: &str) -> String {
+        let words = split_ident_words(ident);
+        match self {
+            Self::Lower => words.concat(),
+            Self::Snake => words.join("_"),
+            Self::Kebab => words.join("-"),
+            Self::ScreamingSnake => words
+                .iter()
+                .map(|w| w.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            Self::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| if i == 0 { w.clone() } else { capitalize(w) })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&syn::LitStr> for RenameAll {
+    type Error = syn::Error;
+
+    fn try_from(lit: &syn::LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "lowercase" => Self::Lower,
+            "snake_case" => Self::Snake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!("unknown `rename_all` case convention: `{other}`"),
+                ))
+            }
+        })
+    }
+}
+
+/// Splits a `PascalCase`/`snake_case`/`kebab-case` [`syn::Ident`] string into its lowercased
+/// component words.
+///
+/// [`syn::Ident`]: struct@syn::Ident
+fn split_ident_words(ident: &str) -> Vec<String> {
+    let chars = ident.chars().collect::<Vec<_>>();
+
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            if !current.is_empty() {
+                words.push(current.to_lowercase());
+                current = String::new();
+            }
+            continue;
+        }
+        let starts_new_word = c.is_uppercase()
+            && !current.is_empty()
+            && (!chars[i - 1].is_uppercase()
+                || chars.get(i + 1).is_some_and(char::is_ascii_lowercase));
+        if starts_new_word {
+            words.push(current.to_lowercase());
+            current = String::new();
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current.to_lowercase());
+    }
+    words
+}
+
+/// Capitalizes the first character of `word`, leaving the rest untouched.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Parsed `#[from_str(...)]` attributes.
+///
+/// The same attribute namespace is used both on the enum/struct itself (container-level
+/// `rename_all`) and on its variants (or the unit struct itself, standing in for its sole
+/// match), so all recognized keys live on a single [`Attrs`].
+#[derive(Clone, Default)]
+struct Attrs {
+    /// [`RenameAll`] case conversion, as set via a container-level `rename_all = "..."`.
+    rename_all: Option<RenameAll>,
+
+    /// Explicit literal replacing the ident-derived name, as set via `rename = "..."`.
+    rename: Option<syn::LitStr>,
+
+    /// Additional literals matched to the same value, as set via one or more `alias = "..."`.
+    aliases: Vec<syn::LitStr>,
+
+    /// Whether `s` should first be tried as an integer discriminant, as set via a container-level
+    /// `numeric`.
+    numeric: bool,
+
+    /// Parsed `#[from_str(format = "...")]` template: a struct- or variant-level replacement for
+    /// matching on the name, populating fields from the input instead.
+    format: Option<syn::LitStr>,
+}
+
+impl Attrs {
+    /// Parses all `#[from_str(...)]` attributes out of the provided [`syn::Attribute`]s.
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("from_str") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    out.rename_all = Some(RenameAll::try_from(&lit)?);
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    out.rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("alias") {
+                    out.aliases.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("numeric") {
+                    out.numeric = true;
+                    Ok(())
+                } else if meta.path.is_ident("format") {
+                    out.format = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `from_str` attribute"))
+                }
+            })?;
+        }
+        Ok(out)
+    }
+}
+
 /// Extension of [`syn::Fields`] used by this expansion.
 trait FieldsExt {
     /// Generates a `name`d constructor with the provided `values` assigned to these
@@ -214,10 +1027,10 @@ trait FieldsExt {
     /// # Panics
     ///
     /// If number of provided `values` doesn't match number of these [`syn::Fields`].
-    fn constructor(
+    fn constructor<V: ToTokens>(
         &self,
         name: &syn::Path,
-        values: impl IntoIterator<Item = syn::Ident>,
+        values: impl IntoIterator<Item = V>,
     ) -> TokenStream;
 
     /// Generates a `Self` type constructor with the provided `values` assigned to these
@@ -226,9 +1039,9 @@ trait FieldsExt {
     /// # Panics
     ///
     /// If number of provided `values` doesn't match number of these [`syn::Fields`].
-    fn self_constructor(
+    fn self_constructor<V: ToTokens>(
         &self,
-        values: impl IntoIterator<Item = syn::Ident>,
+        values: impl IntoIterator<Item = V>,
     ) -> TokenStream {
         self.constructor(&self.self_ty(), values)
     }
@@ -241,7 +1054,7 @@ trait FieldsExt {
     ///
     /// [empty]: syn::Fields::is_empty
     fn self_constructor_empty(&self) -> TokenStream {
-        self.self_constructor(iter::empty())
+        self.self_constructor(iter::empty::<syn::Ident>())
     }
 
     /// Returns a [`syn::Path`] representing a `Self` type of these [`syn::Fields`].
@@ -251,10 +1064,10 @@ trait FieldsExt {
 }
 
 impl FieldsExt for syn::Fields {
-    fn constructor(
+    fn constructor<V: ToTokens>(
         &self,
         name: &syn::Path,
-        values: impl IntoIterator<Item = syn::Ident>,
+        values: impl IntoIterator<Item = V>,
     ) -> TokenStream {
         let values = values.into_iter();
         let fields = match self {
@@ -273,10 +1086,10 @@ impl FieldsExt for syn::Fields {
 }
 
 impl FieldsExt for syn::Field {
-    fn constructor(
+    fn constructor<V: ToTokens>(
         &self,
         name: &syn::Path,
-        values: impl IntoIterator<Item = syn::Ident>,
+        values: impl IntoIterator<Item = V>,
     ) -> TokenStream {
         let mut values = values.into_iter();
         let value = values.next().expect("expected a single value");
@@ -293,10 +1106,10 @@ impl FieldsExt for syn::Field {
 }
 
 impl FieldsExt for syn::Variant {
-    fn constructor(
+    fn constructor<V: ToTokens>(
         &self,
         name: &syn::Path,
-        values: impl IntoIterator<Item = syn::Ident>,
+        values: impl IntoIterator<Item = V>,
     ) -> TokenStream {
         self.fields.constructor(name, values)
     }
@@ -309,20 +1122,20 @@ impl FieldsExt for syn::Variant {
 }
 
 impl FieldsExt for syn::DataStruct {
-    fn constructor(
+    fn constructor<V: ToTokens>(
         &self,
         name: &syn::Path,
-        values: impl IntoIterator<Item = syn::Ident>,
+        values: impl IntoIterator<Item = V>,
     ) -> TokenStream {
         self.fields.constructor(name, values)
     }
 }
 
 impl<L: FieldsExt, R: FieldsExt> FieldsExt for Either<&L, &R> {
-    fn constructor(
+    fn constructor<V: ToTokens>(
         &self,
         name: &syn::Path,
-        values: impl IntoIterator<Item = syn::Ident>,
+        values: impl IntoIterator<Item = V>,
     ) -> TokenStream {
         match self {
             Self::Left(l) => l.constructor(name, values),
@@ -337,3 +1150,363 @@ impl<L: FieldsExt, R: FieldsExt> FieldsExt for Either<&L, &R> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_rejects_adjacent_placeholders() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(format = "{x}{y}")]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+        };
+
+        let err = match FormatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("adjacent"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn format_rejects_unconsumed_trailing_input() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(format = "{x}-{y}end")]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+        };
+
+        let expansion = match FormatExpansion::try_from(&input) {
+            Ok(expansion) => expansion,
+            Err(err) => panic!("should parse: {err}"),
+        };
+
+        let tokens = expansion.to_token_stream().to_string();
+        assert!(
+            tokens.contains("is_empty"),
+            "expected a trailing-input check, got: {tokens}",
+        );
+    }
+
+    #[test]
+    fn format_accepts_separated_placeholders() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(format = "{x}-{y}")]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+        };
+
+        assert!(FormatExpansion::try_from(&input).is_ok());
+    }
+
+    #[test]
+    fn forward_picks_the_sole_unannotated_field() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct Foo {
+                #[from_str(default)]
+                label: String,
+                value: i32,
+            }
+        };
+
+        let expansion = match ForwardExpansion::try_from(&input) {
+            Ok(expansion) => expansion,
+            Err(err) => panic!("should parse: {err}"),
+        };
+        assert_eq!(expansion.parsed, 1);
+    }
+
+    #[test]
+    fn forward_rejects_no_unannotated_field() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct Foo {
+                #[from_str(default)]
+                label: String,
+                #[from_str(value = "1")]
+                value: i32,
+            }
+        };
+
+        let err = match ForwardExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("but none was"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn forward_rejects_multiple_unannotated_fields() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct Foo {
+                label: String,
+                value: i32,
+            }
+        };
+
+        let err = match ForwardExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("but multiple were"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn forward_field_attrs_rejects_default_and_value_together() {
+        let attrs: Vec<syn::Attribute> = vec![parse_quote! {
+            #[from_str(default, value = "1")]
+        }];
+
+        let err = match ForwardFieldAttrs::parse(&attrs) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("mutually exclusive"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn split_ident_words_handles_pascal_case() {
+        assert_eq!(split_ident_words("FooBar"), vec!["foo", "bar"]);
+    }
+
+    #[test]
+    fn split_ident_words_handles_acronyms() {
+        assert_eq!(split_ident_words("HTTPCode"), vec!["http", "code"]);
+    }
+
+    #[test]
+    fn split_ident_words_handles_snake_and_kebab_case() {
+        assert_eq!(split_ident_words("foo_bar"), vec!["foo", "bar"]);
+        assert_eq!(split_ident_words("foo-bar"), vec!["foo", "bar"]);
+    }
+
+    #[test]
+    fn rename_all_converts_every_case() {
+        assert_eq!(RenameAll::Lower.convert("FooBar"), "foobar");
+        assert_eq!(RenameAll::Snake.convert("FooBar"), "foo_bar");
+        assert_eq!(RenameAll::Kebab.convert("FooBar"), "foo-bar");
+        assert_eq!(
+            RenameAll::ScreamingSnake.convert("FooBar"),
+            "FOO_BAR",
+        );
+        assert_eq!(RenameAll::Pascal.convert("foo_bar"), "FooBar");
+        assert_eq!(RenameAll::Camel.convert("foo_bar"), "fooBar");
+    }
+
+    #[test]
+    fn rename_all_rejects_colliding_names() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(rename_all = "snake_case")]
+            enum Foo {
+                HTTPCode,
+                HttpCode,
+            }
+        };
+
+        let err = match FlatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("http_code"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn rename_all_accepts_distinct_names() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(rename_all = "snake_case")]
+            enum Foo {
+                HttpCode,
+                GrpcCode,
+            }
+        };
+
+        assert!(FlatExpansion::try_from(&input).is_ok());
+    }
+
+    #[test]
+    fn rename_all_rejects_on_individual_variant() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Color {
+                #[from_str(rename_all = "kebab-case")]
+                LightBlue,
+                DarkRed,
+            }
+        };
+
+        let err = match FlatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("only applicable to the container"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn numeric_rejects_struct() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(numeric)]
+            struct Foo;
+        };
+
+        let err = match FlatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("only applicable to enums"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn numeric_accepts_enum() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(numeric)]
+            enum Foo {
+                Bar = 1,
+                Baz,
+            }
+        };
+
+        let expansion = match FlatExpansion::try_from(&input) {
+            Ok(expansion) => expansion,
+            Err(err) => panic!("should parse: {err}"),
+        };
+        assert!(expansion.numeric);
+        assert_eq!(expansion.matches[0].discriminant, Some(1));
+        assert_eq!(expansion.matches[1].discriminant, Some(2));
+    }
+
+    #[test]
+    fn numeric_rejects_on_individual_variant() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Color {
+                #[from_str(numeric)]
+                LightBlue,
+                DarkRed,
+            }
+        };
+
+        let err = match FlatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("only applicable to the container"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn variant_format_parses_fielded_variant() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Shape {
+                #[from_str(format = "circle:{r}")]
+                Circle { r: i32 },
+                Square,
+            }
+        };
+
+        let expansion = match FlatExpansion::try_from(&input) {
+            Ok(expansion) => expansion,
+            Err(err) => panic!("should parse: {err}"),
+        };
+        assert!(expansion.matches[0].format.is_some());
+        assert!(expansion.matches[1].format.is_none());
+
+        let tokens = expansion.to_token_stream().to_string();
+        assert!(tokens.contains("circle:"), "unexpected tokens: {tokens}");
+    }
+
+    #[test]
+    fn variant_format_rejects_uncovered_fields() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Shape {
+                #[from_str(format = "circle")]
+                Circle { r: i32 },
+            }
+        };
+
+        let err = match FlatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("not mentioned"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn variant_format_rejects_combination_with_numeric() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[from_str(numeric)]
+            enum Shape {
+                #[from_str(format = "circle:{r}")]
+                Circle { r: i32 },
+                Square,
+            }
+        };
+
+        let err = match FlatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("no integer discriminant"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn variant_without_format_still_rejects_fields() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Shape {
+                Circle { r: i32 },
+                Square,
+            }
+        };
+
+        let err = match FlatExpansion::try_from(&input) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("only enums with no fields"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn non_numeric_enum_allows_non_literal_discriminants() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Level {
+                Low = 1 + 1,
+                High,
+            }
+        };
+
+        let expansion = match FlatExpansion::try_from(&input) {
+            Ok(expansion) => expansion,
+            Err(err) => panic!("should parse: {err}"),
+        };
+        assert!(!expansion.numeric);
+        assert_eq!(expansion.matches[0].discriminant, None);
+        assert_eq!(expansion.matches[1].discriminant, None);
+    }
+}